@@ -0,0 +1,207 @@
+//! HTTP response caching for conditional requests
+//!
+//! GitHub's search endpoints enforce a tight rate limit (30 requests per
+//! minute), but a `304 Not Modified` response to a conditional `GET` does
+//! not count against it. An `HttpCache` lets a `Github` client remember the
+//! `ETag` (and `Link` pagination headers) returned for a url, so the next
+//! request to that same url can be sent with `If-None-Match` and, on a
+//! `304`, the cached body is replayed instead of spending rate limit
+//! budget.
+//!
+//! `Github::get`/`get_pages` are the integration point: before issuing a
+//! request they call `cached_etag` to find an `ETag` to send as
+//! `If-None-Match`, then once the (possibly `304`) response comes back they
+//! call `resolve` to either replay the cached body and `Link` headers or
+//! store the fresh ones for next time. The lookup and the request happen on
+//! either side of an async hyper call, so the two are separate functions
+//! rather than one that wraps the request itself.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// A cached response body together with the validator and pagination
+/// headers needed to replay it
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    /// value of the `ETag` header returned with the cached body
+    pub etag: String,
+    /// raw response body, ready to be handed back on a `304`
+    pub body: Vec<u8>,
+    /// raw `Link` header values seen on the cached response, so
+    /// `get_pages`/`unfold` keep paginating correctly when served from cache
+    pub links: Vec<String>,
+}
+
+/// Something capable of storing and recalling HTTP responses keyed by the
+/// request url they were fetched from
+///
+/// Implement this trait to plug a custom store (e.g. on-disk) into a
+/// `Github` client. `SimpleHttpCache` provides an in-memory default backed
+/// by a `HashMap`.
+pub trait HttpCache: Send + Sync {
+    /// look up a previously cached response for `url`
+    fn get(&self, url: &str) -> Option<CachedResponse>;
+    /// store (or replace) the response cached for `url`
+    fn put(&self, url: &str, response: CachedResponse);
+}
+
+/// An in-memory `HttpCache` backed by a `HashMap`
+///
+/// Handy for short lived processes. Long running services that poll search
+/// endpoints across restarts will likely want a persistent `HttpCache`
+/// implementation instead.
+#[derive(Default)]
+pub struct SimpleHttpCache(Mutex<HashMap<String, CachedResponse>>);
+
+impl SimpleHttpCache {
+    pub fn new() -> Self {
+        SimpleHttpCache(Mutex::new(HashMap::new()))
+    }
+}
+
+impl HttpCache for SimpleHttpCache {
+    fn get(&self, url: &str) -> Option<CachedResponse> {
+        self.0.lock().unwrap().get(url).cloned()
+    }
+
+    fn put(&self, url: &str, response: CachedResponse) {
+        self.0.lock().unwrap().insert(url.to_owned(), response);
+    }
+}
+
+/// The bits of a raw HTTP response `resolve` needs in order to decide
+/// whether to serve a cached body or store a fresh one
+pub struct RawResponse {
+    /// true if the server replied `304 Not Modified`
+    pub not_modified: bool,
+    /// the `ETag` response header, if the server sent one
+    pub etag: Option<String>,
+    /// raw `Link` response header values, if the server sent any
+    pub links: Vec<String>,
+    /// the response body. Empty on a `304`
+    pub body: Vec<u8>,
+}
+
+/// The `ETag` to send as `If-None-Match`, if `cache` has already seen a
+/// response for `url`
+///
+/// Call this before issuing a request so the conditional header can be
+/// attached; pair it with `resolve` once the response comes back.
+pub fn cached_etag(cache: &HttpCache, url: &str) -> Option<String> {
+    cache.get(url).map(|entry| entry.etag)
+}
+
+/// Resolve a `RawResponse` for `url` against `cache`
+///
+/// On a `304 Not Modified`, replays the previously cached body and `Link`
+/// headers instead of the (empty) response body -- this is what lets a
+/// conditional request avoid spending GitHub's rate limit. Otherwise the
+/// fresh response is stored (when it carries an `ETag`) and returned as-is.
+pub fn resolve(cache: &HttpCache, url: &str, response: RawResponse) -> (Vec<u8>, Vec<String>) {
+    if response.not_modified {
+        if let Some(cached) = cache.get(url) {
+            return (cached.body, cached.links);
+        }
+    }
+
+    if let Some(etag) = response.etag {
+        cache.put(
+            url,
+            CachedResponse {
+                etag,
+                body: response.body.clone(),
+                links: response.links.clone(),
+            },
+        );
+    }
+
+    (response.body, response.links)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cached_etag_returns_none_when_nothing_is_cached() {
+        let cache = SimpleHttpCache::new();
+        assert_eq!(cached_etag(&cache, "/search/issues?q=foo"), None);
+    }
+
+    #[test]
+    fn resolve_replays_cached_body_on_304() {
+        let cache = SimpleHttpCache::new();
+        cache.put(
+            "/search/issues?q=foo",
+            CachedResponse {
+                etag: "abc123".to_string(),
+                body: b"{\"cached\":true}".to_vec(),
+                links: vec!["<https://api.github.com/search/issues?q=foo&page=2>; rel=\"next\"".to_string()],
+            },
+        );
+
+        assert_eq!(cached_etag(&cache, "/search/issues?q=foo"), Some("abc123".to_string()));
+
+        let (body, links) = resolve(
+            &cache,
+            "/search/issues?q=foo",
+            RawResponse {
+                not_modified: true,
+                etag: None,
+                links: Vec::new(),
+                body: Vec::new(),
+            },
+        );
+
+        assert_eq!(body, b"{\"cached\":true}".to_vec());
+        assert_eq!(
+            links,
+            vec!["<https://api.github.com/search/issues?q=foo&page=2>; rel=\"next\"".to_string()]
+        );
+    }
+
+    #[test]
+    fn resolve_stores_fresh_response_with_etag() {
+        let cache = SimpleHttpCache::new();
+
+        let (body, links) = resolve(
+            &cache,
+            "/search/issues?q=foo",
+            RawResponse {
+                not_modified: false,
+                etag: Some("xyz789".to_string()),
+                links: vec!["<https://api.github.com/search/issues?q=foo&page=2>; rel=\"next\"".to_string()],
+                body: b"{\"cached\":false}".to_vec(),
+            },
+        );
+
+        assert_eq!(body, b"{\"cached\":false}".to_vec());
+        assert_eq!(
+            links,
+            vec!["<https://api.github.com/search/issues?q=foo&page=2>; rel=\"next\"".to_string()]
+        );
+
+        let cached = cache.get("/search/issues?q=foo").unwrap();
+        assert_eq!(cached.etag, "xyz789");
+        assert_eq!(cached.body, b"{\"cached\":false}".to_vec());
+        assert_eq!(cached.links, links);
+    }
+
+    #[test]
+    fn resolve_does_not_cache_responses_without_an_etag() {
+        let cache = SimpleHttpCache::new();
+
+        resolve(
+            &cache,
+            "/search/issues?q=foo",
+            RawResponse {
+                not_modified: false,
+                etag: None,
+                links: Vec::new(),
+                body: b"{\"cached\":false}".to_vec(),
+            },
+        );
+
+        assert!(cache.get("/search/issues?q=foo").is_none());
+    }
+}
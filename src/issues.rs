@@ -0,0 +1,21 @@
+//! Issues interface
+
+use std::fmt;
+
+/// State of an issue or pull request
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum State {
+    /// the issue/pull request is open
+    Open,
+    /// the issue/pull request is closed
+    Closed,
+}
+
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            State::Open => "open",
+            State::Closed => "closed",
+        }.fmt(f)
+    }
+}
@@ -0,0 +1,333 @@
+//! Hubcaps provides a set of building blocks for interacting with the
+//! GitHub API
+
+extern crate chrono;
+extern crate futures;
+extern crate hyper;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+#[macro_use]
+extern crate serde_json;
+extern crate url;
+
+use std::fmt;
+use std::sync::Arc;
+
+use futures::{Future as StdFuture, Stream as StdStream};
+use futures::stream;
+use hyper::client::{Connect, HttpConnector};
+use hyper::header::{
+    Authorization, ContentType, ETag, EntityTag, Headers, IfNoneMatch, Raw, UserAgent,
+};
+use hyper::{Body, Client, Method, Request, StatusCode};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+pub mod http_cache;
+pub mod issues;
+pub mod labels;
+pub mod search;
+pub mod users;
+
+use http_cache::{cached_etag, resolve, HttpCache, RawResponse};
+
+/// Errors surfaced by `Github` requests
+#[derive(Debug)]
+pub enum Error {
+    /// the response body could not be decoded as the expected type
+    Codec(serde_json::Error),
+    /// the underlying HTTP request failed
+    Http(hyper::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Codec(ref err) => write!(f, "error decoding response body: {}", err),
+            Error::Http(ref err) => write!(f, "error sending request: {}", err),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {
+    fn description(&self) -> &str {
+        "error communicating with the GitHub API"
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Error {
+        Error::Codec(err)
+    }
+}
+
+impl From<hyper::Error> for Error {
+    fn from(err: hyper::Error) -> Error {
+        Error::Http(err)
+    }
+}
+
+/// A boxed future yielding a `T` or an `Error`
+pub type Future<T> = Box<StdFuture<Item = T, Error = Error> + Send>;
+
+/// A boxed stream yielding `T`s or an `Error`
+pub type Stream<T> = Box<StdStream<Item = T, Error = Error> + Send>;
+
+/// Sort ordering direction used by list/search options builders
+#[derive(Debug, PartialEq)]
+pub enum SortDirection {
+    /// ascending order
+    Asc,
+    /// descending order
+    Desc,
+}
+
+impl fmt::Display for SortDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SortDirection::Asc => "asc",
+            SortDirection::Desc => "desc",
+        }.fmt(f)
+    }
+}
+
+/// Credentials used to authenticate requests against the GitHub API
+#[derive(Debug, Clone)]
+pub enum Credentials {
+    /// a personal access token / OAuth token, sent as a `token` scheme
+    /// `Authorization` header
+    Token(String),
+}
+
+/// find the `rel="next"` url in a set of raw `Link` header values, if any
+fn next_page_url(links: &[String]) -> Option<String> {
+    links
+        .iter()
+        .flat_map(|raw| raw.split(','))
+        .filter(|segment| segment.contains("rel=\"next\""))
+        .filter_map(|segment| {
+            segment
+                .split(';')
+                .next()
+                .map(|part| part.trim().trim_start_matches('<').trim_end_matches('>').to_string())
+        })
+        .next()
+}
+
+/// Unfold successive pages of a paginated endpoint into a single `Stream`
+///
+/// `first` resolves to the raw `Link` header values and deserialized payload
+/// of the first page; `into_items` extracts the items from a page's payload
+/// so they can be flattened into the resulting stream. Subsequent pages are
+/// fetched (and, same as the first page, served from cache on a `304`) by
+/// following the `rel="next"` url until none remains.
+pub fn unfold<C, D, I>(
+    github: Github<C>,
+    first: Future<(Vec<String>, I)>,
+    into_items: fn(I) -> Vec<D>,
+) -> Stream<D>
+where
+    C: Clone + Connect + 'static,
+    D: DeserializeOwned + 'static,
+    I: DeserializeOwned + 'static,
+{
+    Box::new(
+        first
+            .map(move |(links, payload)| {
+                let first_items = into_items(payload);
+                let next = next_page_url(&links);
+                stream::iter_ok(first_items).chain(
+                    stream::unfold((github, next), move |(github, next)| {
+                        next.map(|url| {
+                            github.get_pages::<I>(&url).map(move |(links, payload)| {
+                                let items = stream::iter_ok(into_items(payload));
+                                (items, (github.clone(), next_page_url(&links)))
+                            })
+                        })
+                    }).flatten(),
+                )
+            })
+            .flatten_stream(),
+    )
+}
+
+/// Entry point for interacting with the GitHub API
+#[derive(Clone)]
+pub struct Github<C>
+where
+    C: Clone + Connect,
+{
+    host: String,
+    agent: String,
+    client: Arc<Client<C>>,
+    credentials: Option<Credentials>,
+    cache: Option<Arc<HttpCache>>,
+}
+
+impl Github<HttpConnector> {
+    /// create a new Github client using the default hyper `HttpConnector`
+    pub fn new<A>(agent: A, credentials: Option<Credentials>) -> Self
+    where
+        A: Into<String>,
+    {
+        Self::host("https://api.github.com", agent, Client::new(), credentials)
+    }
+}
+
+impl<C: Clone + Connect + 'static> Github<C> {
+    /// create a new Github client pointed at a custom host (e.g. a GitHub
+    /// Enterprise instance), using a caller-supplied hyper `Client`
+    pub fn host<H, A>(host: H, agent: A, client: Client<C>, credentials: Option<Credentials>) -> Self
+    where
+        H: Into<String>,
+        A: Into<String>,
+    {
+        Self {
+            host: host.into(),
+            agent: agent.into(),
+            client: Arc::new(client),
+            credentials,
+            cache: None,
+        }
+    }
+
+    /// return a copy of this client with `cache` wired in, so `GET`s become
+    /// conditional requests and `304`s replay the cached body instead of
+    /// spending GitHub's rate limit
+    pub fn with_http_cache(mut self, cache: Arc<HttpCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// return a reference to a search interface
+    pub fn search(&self) -> search::Search<C> {
+        search::Search::new(self.clone())
+    }
+
+    fn url_for(&self, uri: &str) -> String {
+        if uri.starts_with("http") {
+            uri.to_owned()
+        } else {
+            format!("{}{}", self.host, uri)
+        }
+    }
+
+    fn request<D>(&self, method: Method, uri: &str, body: Option<Value>) -> Future<(Vec<String>, D)>
+    where
+        D: DeserializeOwned + 'static,
+    {
+        let url = self.url_for(uri);
+        let mut headers = Headers::new();
+        headers.set(UserAgent::new(self.agent.clone()));
+        if let Some(Credentials::Token(ref token)) = self.credentials {
+            headers.set(Authorization(format!("token {}", token)));
+        }
+
+        // GET is the only method that can be served from cache, so this is
+        // the only one where we bother looking for a cached `ETag`
+        let if_none_match = if method == Method::Get {
+            self.cache.as_ref().and_then(|cache| cached_etag(cache.as_ref(), &url))
+        } else {
+            None
+        };
+        if let Some(etag) = if_none_match {
+            headers.set(IfNoneMatch::Items(vec![EntityTag::new(false, etag)]));
+        }
+
+        let mut req = Request::new(method.clone(), url.parse().unwrap());
+        *req.headers_mut() = headers;
+        if let Some(ref body) = body {
+            req.headers_mut().set(ContentType::json());
+            req.set_body(Body::from(body.to_string()));
+        }
+
+        let cache = self.cache.clone();
+        let url_for_cache = url.clone();
+        let is_get = method == Method::Get;
+
+        Box::new(
+            self.client
+                .request(req)
+                .map_err(Error::from)
+                .and_then(move |res| {
+                    let not_modified = res.status() == StatusCode::NotModified;
+                    let etag = res.headers().get::<ETag>().map(|tag| tag.tag().to_string());
+                    let links = res.headers()
+                        .get_raw("Link")
+                        .map(|raw: &Raw| {
+                            raw.iter()
+                                .map(|line| String::from_utf8_lossy(line).into_owned())
+                                .collect()
+                        })
+                        .unwrap_or_else(Vec::new);
+
+                    res.body().concat2().map_err(Error::from).map(move |chunk| {
+                        RawResponse {
+                            not_modified,
+                            etag,
+                            links,
+                            body: chunk.to_vec(),
+                        }
+                    })
+                })
+                .and_then(move |raw| {
+                    let (body, links) = match cache {
+                        Some(ref cache) if is_get => resolve(cache.as_ref(), &url_for_cache, raw),
+                        _ => (raw.body, raw.links),
+                    };
+                    let payload = serde_json::from_slice(&body)?;
+                    Ok((links, payload))
+                }),
+        )
+    }
+
+    /// GET `uri`, deserializing the response body as `D`
+    ///
+    /// If an `HttpCache` is configured and a previous response for `uri` is
+    /// on file, this sends `If-None-Match` and replays the cached body on a
+    /// `304` instead of spending rate limit.
+    pub fn get<D>(&self, uri: &str) -> Future<D>
+    where
+        D: DeserializeOwned + 'static,
+    {
+        Box::new(self.request(Method::Get, uri, None).map(|(_, payload)| payload))
+    }
+
+    /// GET a page of `uri`, returning the raw `Link` header values alongside
+    /// the deserialized payload so callers (namely `unfold`) can keep
+    /// paginating. Cache-aware in the same way as `get`.
+    pub fn get_pages<D>(&self, uri: &str) -> Future<(Vec<String>, D)>
+    where
+        D: DeserializeOwned + 'static,
+    {
+        self.request(Method::Get, uri, None)
+    }
+
+    /// POST `body` to `uri`, deserializing the response body as `D`
+    pub fn post<D>(&self, uri: &str, body: Value) -> Future<D>
+    where
+        D: DeserializeOwned + 'static,
+    {
+        Box::new(self.request(Method::Post, uri, Some(body)).map(
+            |(_, payload)| payload,
+        ))
+    }
+
+    /// PATCH `body` to `uri`, deserializing the response body as `D`
+    pub fn patch<D>(&self, uri: &str, body: Value) -> Future<D>
+    where
+        D: DeserializeOwned + 'static,
+    {
+        Box::new(self.request(Method::Patch, uri, Some(body)).map(
+            |(_, payload)| payload,
+        ))
+    }
+
+    /// DELETE `uri`
+    pub fn delete(&self, uri: &str) -> Future<()> {
+        Box::new(self.request::<Value>(Method::Delete, uri, None).map(
+            |_| (),
+        ))
+    }
+}
@@ -0,0 +1,143 @@
+//! Search code interface
+
+use std::collections::HashMap;
+use std::fmt;
+
+use hyper::client::Connect;
+use url::form_urlencoded;
+
+use {Future, SortDirection, Stream};
+use search::{Search, SearchRepo, SearchResult};
+
+/// Sort directions for code search results
+///
+/// Code search only supports sorting by `indexed`, but this still mirrors
+/// the `*Sort` enum + `sort(..)` idiom used by the other search options so
+/// callers don't need to special-case it.
+#[derive(Debug, PartialEq)]
+pub enum CodeSort {
+    /// Sort by how recently a file was indexed
+    Indexed,
+}
+
+impl fmt::Display for CodeSort {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CodeSort::Indexed => "indexed",
+        }.fmt(f)
+    }
+}
+
+/// Provides access to code search operations
+/// https://developer.github.com/v3/search/#search-code
+pub struct SearchCode<C>
+where
+    C: Clone + Connect,
+{
+    search: Search<C>,
+}
+
+impl<C: Clone + Connect> SearchCode<C> {
+    #[doc(hidden)]
+    pub fn new(search: Search<C>) -> Self {
+        Self { search }
+    }
+
+    fn search_uri<Q>(&self, q: Q, options: &SearchCodeOptions) -> String
+    where
+        Q: Into<String>,
+    {
+        let mut uri = vec!["/search/code".to_string()];
+        let query_options = options.serialize().unwrap_or(String::new());
+        let query = form_urlencoded::Serializer::new(query_options)
+            .append_pair("q", &q.into())
+            .finish();
+        uri.push(query);
+        uri.join("?")
+    }
+
+    /// Returns an Iterator over pages of search results
+    /// Use this interface if you wish to iterate over all items
+    /// in a result set
+    pub fn iter<Q>(&self, q: Q, options: &SearchCodeOptions) -> Stream<CodeItem>
+    where
+        Q: Into<String>,
+    {
+        self.search.iter::<CodeItem>(&self.search_uri(q, options))
+    }
+
+    /// Returns a single page of search results
+    pub fn list<Q>(&self, q: Q, options: &SearchCodeOptions) -> Future<SearchResult<CodeItem>>
+    where
+        Q: Into<String>,
+    {
+        self.search.search::<CodeItem>(
+            &self.search_uri(q, options),
+        )
+    }
+}
+
+// representations (todo: replace with derive_builder)
+
+#[derive(Default)]
+pub struct SearchCodeOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl SearchCodeOptions {
+    pub fn builder() -> SearchCodeOptionsBuilder {
+        SearchCodeOptionsBuilder::new()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+/// https://developer.github.com/v3/search/#search-code
+pub struct SearchCodeOptionsBuilder(SearchCodeOptions);
+
+impl SearchCodeOptionsBuilder {
+    pub fn new() -> SearchCodeOptionsBuilder {
+        SearchCodeOptionsBuilder(SearchCodeOptions { ..Default::default() })
+    }
+
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn sort(&mut self, sort: CodeSort) -> &mut Self {
+        self.0.params.insert("sort", sort.to_string());
+        self
+    }
+
+    pub fn order(&mut self, direction: SortDirection) -> &mut Self {
+        self.0.params.insert("order", direction.to_string());
+        self
+    }
+
+    pub fn build(&self) -> SearchCodeOptions {
+        SearchCodeOptions { params: self.0.params.clone() }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CodeItem {
+    pub name: String,
+    pub path: String,
+    pub sha: String,
+    pub url: String,
+    pub git_url: String,
+    pub html_url: String,
+    pub repository: SearchRepo,
+    pub score: f64,
+}
@@ -0,0 +1,169 @@
+//! Search commits interface
+
+use std::collections::HashMap;
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use hyper::client::Connect;
+use url::form_urlencoded;
+
+use {Future, SortDirection, Stream};
+use users::User;
+use search::{deserialize_date_time, Search, SearchRepo, SearchResult};
+
+/// Sort directions for commit search results
+#[derive(Debug, PartialEq)]
+pub enum CommitsSort {
+    /// Sort by author date
+    AuthorDate,
+    /// Sort by committer date
+    CommitterDate,
+}
+
+impl fmt::Display for CommitsSort {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CommitsSort::AuthorDate => "author-date",
+            CommitsSort::CommitterDate => "committer-date",
+        }.fmt(f)
+    }
+}
+
+/// Provides access to commit search operations
+/// https://developer.github.com/v3/search/#search-commits
+pub struct SearchCommits<C>
+where
+    C: Clone + Connect,
+{
+    search: Search<C>,
+}
+
+impl<C: Clone + Connect> SearchCommits<C> {
+    #[doc(hidden)]
+    pub fn new(search: Search<C>) -> Self {
+        Self { search }
+    }
+
+    fn search_uri<Q>(&self, q: Q, options: &SearchCommitsOptions) -> String
+    where
+        Q: Into<String>,
+    {
+        let mut uri = vec!["/search/commits".to_string()];
+        let query_options = options.serialize().unwrap_or(String::new());
+        let query = form_urlencoded::Serializer::new(query_options)
+            .append_pair("q", &q.into())
+            .finish();
+        uri.push(query);
+        uri.join("?")
+    }
+
+    /// Returns an Iterator over pages of search results
+    /// Use this interface if you wish to iterate over all items
+    /// in a result set
+    pub fn iter<Q>(&self, q: Q, options: &SearchCommitsOptions) -> Stream<CommitItem>
+    where
+        Q: Into<String>,
+    {
+        self.search.iter::<CommitItem>(&self.search_uri(q, options))
+    }
+
+    /// Returns a single page of search results
+    pub fn list<Q>(&self, q: Q, options: &SearchCommitsOptions) -> Future<SearchResult<CommitItem>>
+    where
+        Q: Into<String>,
+    {
+        self.search.search::<CommitItem>(
+            &self.search_uri(q, options),
+        )
+    }
+}
+
+// representations (todo: replace with derive_builder)
+
+#[derive(Default)]
+pub struct SearchCommitsOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl SearchCommitsOptions {
+    pub fn builder() -> SearchCommitsOptionsBuilder {
+        SearchCommitsOptionsBuilder::new()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+/// https://developer.github.com/v3/search/#search-commits
+pub struct SearchCommitsOptionsBuilder(SearchCommitsOptions);
+
+impl SearchCommitsOptionsBuilder {
+    pub fn new() -> SearchCommitsOptionsBuilder {
+        SearchCommitsOptionsBuilder(SearchCommitsOptions { ..Default::default() })
+    }
+
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn sort(&mut self, sort: CommitsSort) -> &mut Self {
+        self.0.params.insert("sort", sort.to_string());
+        self
+    }
+
+    pub fn order(&mut self, direction: SortDirection) -> &mut Self {
+        self.0.params.insert("order", direction.to_string());
+        self
+    }
+
+    pub fn build(&self) -> SearchCommitsOptions {
+        SearchCommitsOptions { params: self.0.params.clone() }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommitAuthorInfo {
+    pub name: String,
+    pub email: String,
+    #[serde(deserialize_with = "deserialize_date_time")]
+    pub date: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommitTreeInfo {
+    pub sha: String,
+    pub url: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommitDetail {
+    pub author: CommitAuthorInfo,
+    pub committer: CommitAuthorInfo,
+    pub message: String,
+    pub tree: CommitTreeInfo,
+    pub url: String,
+    pub comment_count: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommitItem {
+    pub url: String,
+    pub sha: String,
+    pub html_url: String,
+    pub comments_url: String,
+    pub commit: CommitDetail,
+    pub author: Option<User>,
+    pub committer: Option<User>,
+    pub repository: SearchRepo,
+    pub score: f64,
+}
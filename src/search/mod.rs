@@ -3,8 +3,10 @@
 use std::collections::HashMap;
 use std::fmt;
 
+use chrono::{DateTime, Utc};
 use hyper::client::Connect;
-use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+use serde::de::{DeserializeOwned, Error};
 use url::{self, form_urlencoded};
 
 use {Github, Stream, Future, SortDirection, unfold};
@@ -14,7 +16,26 @@ use users::User;
 mod repos;
 use self::repos::SearchRepos;
 
+mod users;
+use self::users::SearchUsers;
+
+mod code;
+use self::code::SearchCode;
+
+mod commits;
+use self::commits::SearchCommits;
+
+mod topics;
+use self::topics::SearchTopics;
+
+mod query;
+
 pub use self::repos::*;
+pub use self::users::*;
+pub use self::code::*;
+pub use self::commits::*;
+pub use self::topics::*;
+pub use self::query::*;
 
 /// Sort directions for pull requests
 #[derive(Debug, PartialEq)]
@@ -47,6 +68,34 @@ where
     github: Github<C>,
 }
 
+/// deserialize an RFC3339 timestamp string into a `DateTime<Utc>`
+fn deserialize_date_time<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    DateTime::parse_from_rfc3339(&value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(Error::custom)
+}
+
+/// deserialize an optional RFC3339 timestamp string into a `Option<DateTime<Utc>>`
+fn deserialize_opt_date_time<'de, D>(
+    deserializer: D,
+) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(ref value) => {
+            DateTime::parse_from_rfc3339(value)
+                .map(|dt| Some(dt.with_timezone(&Utc)))
+                .map_err(Error::custom)
+        }
+        None => Ok(None),
+    }
+}
+
 fn items<D>(result: SearchResult<D>) -> Vec<D>
 where
     D: DeserializeOwned + 'static,
@@ -70,6 +119,26 @@ impl<C: Clone + Connect> Search<C> {
         SearchRepos::new(self.clone())
     }
 
+    /// Return a reference to a search interface for users
+    pub fn users(&self) -> SearchUsers<C> {
+        SearchUsers::new(self.clone())
+    }
+
+    /// Return a reference to a search interface for code
+    pub fn code(&self) -> SearchCode<C> {
+        SearchCode::new(self.clone())
+    }
+
+    /// Return a reference to a search interface for commits
+    pub fn commits(&self) -> SearchCommits<C> {
+        SearchCommits::new(self.clone())
+    }
+
+    /// Return a reference to a search interface for topics
+    pub fn topics(&self) -> SearchTopics<C> {
+        SearchTopics::new(self.clone())
+    }
+
     fn iter<D>(&self, url: &str) -> Stream<D>
     where
         D: DeserializeOwned + 'static,
@@ -194,6 +263,24 @@ pub struct SearchResult<D> {
     pub items: Vec<D>,
 }
 
+/// The reduced repository representation GitHub nests inside code and
+/// commit search results
+///
+/// This is *not* the full `RepoItem` returned by repo search -- code/commit
+/// search only embeds this smaller subset of fields, so reusing `RepoItem`
+/// here would fail to deserialize.
+#[derive(Debug, Deserialize)]
+pub struct SearchRepo {
+    pub id: u64,
+    pub name: String,
+    pub full_name: String,
+    pub owner: User,
+    pub private: bool,
+    pub html_url: String,
+    pub description: Option<String>,
+    pub fork: bool,
+    pub url: String,
+}
 
 #[derive(Debug, Deserialize)]
 pub struct IssuesItem {
@@ -213,9 +300,12 @@ pub struct IssuesItem {
     pub assignee: Option<User>,
     pub assignees: Vec<User>,
     pub comments: u64,
-    pub created_at: String,
-    pub updated_at: String,
-    pub closed_at: Option<String>,
+    #[serde(deserialize_with = "deserialize_date_time")]
+    pub created_at: DateTime<Utc>,
+    #[serde(deserialize_with = "deserialize_date_time")]
+    pub updated_at: DateTime<Utc>,
+    #[serde(deserialize_with = "deserialize_opt_date_time")]
+    pub closed_at: Option<DateTime<Utc>>,
     pub pull_request: Option<PullRequestInfo>,
     pub body: Option<String>,
 }
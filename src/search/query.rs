@@ -0,0 +1,183 @@
+//! Typed query builder for issue/repo search qualifiers
+
+use std::fmt;
+
+use issues::State;
+
+/// A range qualifier for a numeric or date search field
+///
+/// Renders as the qualifier value GitHub expects, e.g. `100`, `>100`,
+/// `>=100`, `<100`, `<=100` or `100..200`.
+#[derive(Debug, Clone)]
+pub enum Range<T>
+where
+    T: fmt::Display,
+{
+    /// an exact value, e.g. `100`
+    Exact(T),
+    /// greater than a value, e.g. `>100`
+    GreaterThan(T),
+    /// greater than or equal to a value, e.g. `>=100`
+    GreaterThanOrEqual(T),
+    /// less than a value, e.g. `<100`
+    LessThan(T),
+    /// less than or equal to a value, e.g. `<=100`
+    LessThanOrEqual(T),
+    /// an inclusive range, e.g. `100..200`
+    Between(T, T),
+}
+
+impl<T> fmt::Display for Range<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Range::Exact(ref value) => write!(f, "{}", value),
+            Range::GreaterThan(ref value) => write!(f, ">{}", value),
+            Range::GreaterThanOrEqual(ref value) => write!(f, ">={}", value),
+            Range::LessThan(ref value) => write!(f, "<{}", value),
+            Range::LessThanOrEqual(ref value) => write!(f, "<={}", value),
+            Range::Between(ref start, ref end) => write!(f, "{}..{}", start, end),
+        }
+    }
+}
+
+/// A range qualifier over dates, e.g. `created:2018-01-01..2018-02-01`
+pub type DateRange = Range<String>;
+
+/// escape a qualifier value, quoting it if it contains whitespace
+fn escape(value: &str) -> String {
+    if value.contains(char::is_whitespace) {
+        format!("\"{}\"", value)
+    } else {
+        value.to_string()
+    }
+}
+
+/// A programmatic builder for the `q` search query GitHub's issue and
+/// repository search endpoints expect
+///
+/// Renders to a correctly space-joined, qualifier-escaped query string via
+/// `Into<String>`, so it plugs directly into `SearchIssues::iter`/`list`
+/// (and any other search interface accepting `Q: Into<String>`).
+///
+/// # Example
+///
+/// ```ignore
+/// let q = Query::new()
+///     .repo("rust-lang", "rust")
+///     .is("open")
+///     .label("E-easy")
+///     .term("panic");
+/// github.search().issues().list(q, &Default::default());
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct Query {
+    qualifiers: Vec<String>,
+    terms: Vec<String>,
+}
+
+impl Query {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// restrict results to a single repository
+    pub fn repo<O, R>(mut self, owner: O, name: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        self.qualifiers.push(
+            format!("repo:{}/{}", owner.into(), name.into()),
+        );
+        self
+    }
+
+    /// restrict results to repositories owned by an organization
+    pub fn org<O>(mut self, org: O) -> Self
+    where
+        O: Into<String>,
+    {
+        self.qualifiers.push(format!("org:{}", org.into()));
+        self
+    }
+
+    /// restrict results to items created by a given user
+    pub fn author<A>(mut self, author: A) -> Self
+    where
+        A: Into<String>,
+    {
+        self.qualifiers.push(
+            format!("author:{}", escape(&author.into())),
+        );
+        self
+    }
+
+    /// restrict results to items assigned to a given user
+    pub fn assignee<A>(mut self, assignee: A) -> Self
+    where
+        A: Into<String>,
+    {
+        self.qualifiers.push(
+            format!("assignee:{}", escape(&assignee.into())),
+        );
+        self
+    }
+
+    /// restrict results to items with a given label
+    pub fn label<L>(mut self, label: L) -> Self
+    where
+        L: Into<String>,
+    {
+        self.qualifiers.push(
+            format!("label:{}", escape(&label.into())),
+        );
+        self
+    }
+
+    /// restrict results to issues/pull requests in a given state
+    pub fn state(mut self, state: State) -> Self {
+        self.qualifiers.push(format!("state:{}", state));
+        self
+    }
+
+    /// restrict results by kind, e.g. `is("open")`, `is("pr")`, `is("public")`
+    pub fn is<K>(mut self, kind: K) -> Self
+    where
+        K: Into<String>,
+    {
+        self.qualifiers.push(format!("is:{}", kind.into()));
+        self
+    }
+
+    /// restrict results to a date range on when the item was created
+    pub fn created(mut self, range: DateRange) -> Self {
+        self.qualifiers.push(format!("created:{}", range));
+        self
+    }
+
+    /// restrict results to a range on the number of comments
+    pub fn comments(mut self, range: Range<u64>) -> Self {
+        self.qualifiers.push(format!("comments:{}", range));
+        self
+    }
+
+    /// add free-text search terms
+    pub fn term<T>(mut self, term: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.terms.push(escape(&term.into()));
+        self
+    }
+}
+
+impl From<Query> for String {
+    fn from(query: Query) -> String {
+        let mut parts = query.terms;
+        parts.extend(query.qualifiers);
+        parts.join(" ")
+    }
+}
@@ -0,0 +1,166 @@
+//! Search repositories interface
+
+use std::collections::HashMap;
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use hyper::client::Connect;
+use url::form_urlencoded;
+
+use {Future, SortDirection, Stream};
+use users::User;
+use search::{deserialize_date_time, Search, SearchResult};
+
+/// Sort directions for repository search results
+#[derive(Debug, PartialEq)]
+pub enum RepoSort {
+    /// Sort by number of stars
+    Stars,
+    /// Sort by number of forks
+    Forks,
+    /// Sort by number of help-wanted issues
+    HelpWantedIssues,
+    /// Sort by last updated
+    Updated,
+}
+
+impl fmt::Display for RepoSort {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RepoSort::Stars => "stars",
+            RepoSort::Forks => "forks",
+            RepoSort::HelpWantedIssues => "help-wanted-issues",
+            RepoSort::Updated => "updated",
+        }.fmt(f)
+    }
+}
+
+/// Provides access to repository search operations
+/// https://developer.github.com/v3/search/#search-repositories
+pub struct SearchRepos<C>
+where
+    C: Clone + Connect,
+{
+    search: Search<C>,
+}
+
+impl<C: Clone + Connect> SearchRepos<C> {
+    #[doc(hidden)]
+    pub fn new(search: Search<C>) -> Self {
+        Self { search }
+    }
+
+    fn search_uri<Q>(&self, q: Q, options: &SearchReposOptions) -> String
+    where
+        Q: Into<String>,
+    {
+        let mut uri = vec!["/search/repositories".to_string()];
+        let query_options = options.serialize().unwrap_or(String::new());
+        let query = form_urlencoded::Serializer::new(query_options)
+            .append_pair("q", &q.into())
+            .finish();
+        uri.push(query);
+        uri.join("?")
+    }
+
+    /// Returns an Iterator over pages of search results
+    /// Use this interface if you wish to iterate over all items
+    /// in a result set
+    pub fn iter<Q>(&self, q: Q, options: &SearchReposOptions) -> Stream<RepoItem>
+    where
+        Q: Into<String>,
+    {
+        self.search.iter::<RepoItem>(&self.search_uri(q, options))
+    }
+
+    /// Returns a single page of search results
+    pub fn list<Q>(&self, q: Q, options: &SearchReposOptions) -> Future<SearchResult<RepoItem>>
+    where
+        Q: Into<String>,
+    {
+        self.search.search::<RepoItem>(
+            &self.search_uri(q, options),
+        )
+    }
+}
+
+// representations (todo: replace with derive_builder)
+
+#[derive(Default)]
+pub struct SearchReposOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl SearchReposOptions {
+    pub fn builder() -> SearchReposOptionsBuilder {
+        SearchReposOptionsBuilder::new()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+/// https://developer.github.com/v3/search/#search-repositories
+pub struct SearchReposOptionsBuilder(SearchReposOptions);
+
+impl SearchReposOptionsBuilder {
+    pub fn new() -> SearchReposOptionsBuilder {
+        SearchReposOptionsBuilder(SearchReposOptions { ..Default::default() })
+    }
+
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn sort(&mut self, sort: RepoSort) -> &mut Self {
+        self.0.params.insert("sort", sort.to_string());
+        self
+    }
+
+    pub fn order(&mut self, direction: SortDirection) -> &mut Self {
+        self.0.params.insert("order", direction.to_string());
+        self
+    }
+
+    pub fn build(&self) -> SearchReposOptions {
+        SearchReposOptions { params: self.0.params.clone() }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RepoItem {
+    pub id: u64,
+    pub name: String,
+    pub full_name: String,
+    pub owner: User,
+    pub private: bool,
+    pub html_url: String,
+    pub description: Option<String>,
+    pub fork: bool,
+    pub url: String,
+    #[serde(deserialize_with = "deserialize_date_time")]
+    pub created_at: DateTime<Utc>,
+    #[serde(deserialize_with = "deserialize_date_time")]
+    pub updated_at: DateTime<Utc>,
+    #[serde(deserialize_with = "deserialize_date_time")]
+    pub pushed_at: DateTime<Utc>,
+    pub homepage: Option<String>,
+    pub size: u64,
+    pub stargazers_count: u64,
+    pub watchers_count: u64,
+    pub language: Option<String>,
+    pub forks_count: u64,
+    pub open_issues_count: u64,
+    pub default_branch: String,
+    pub score: f64,
+}
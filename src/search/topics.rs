@@ -0,0 +1,121 @@
+//! Search topics interface
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use hyper::client::Connect;
+use url::form_urlencoded;
+
+use {Future, Stream};
+use search::{deserialize_date_time, Search, SearchResult};
+
+/// Provides access to topic search operations
+/// https://developer.github.com/v3/search/#search-topics
+pub struct SearchTopics<C>
+where
+    C: Clone + Connect,
+{
+    search: Search<C>,
+}
+
+impl<C: Clone + Connect> SearchTopics<C> {
+    #[doc(hidden)]
+    pub fn new(search: Search<C>) -> Self {
+        Self { search }
+    }
+
+    fn search_uri<Q>(&self, q: Q, options: &SearchTopicsOptions) -> String
+    where
+        Q: Into<String>,
+    {
+        let mut uri = vec!["/search/topics".to_string()];
+        let query_options = options.serialize().unwrap_or(String::new());
+        let query = form_urlencoded::Serializer::new(query_options)
+            .append_pair("q", &q.into())
+            .finish();
+        uri.push(query);
+        uri.join("?")
+    }
+
+    /// Returns an Iterator over pages of search results
+    /// Use this interface if you wish to iterate over all items
+    /// in a result set
+    pub fn iter<Q>(&self, q: Q, options: &SearchTopicsOptions) -> Stream<TopicItem>
+    where
+        Q: Into<String>,
+    {
+        self.search.iter::<TopicItem>(&self.search_uri(q, options))
+    }
+
+    /// Returns a single page of search results
+    pub fn list<Q>(&self, q: Q, options: &SearchTopicsOptions) -> Future<SearchResult<TopicItem>>
+    where
+        Q: Into<String>,
+    {
+        self.search.search::<TopicItem>(
+            &self.search_uri(q, options),
+        )
+    }
+}
+
+// representations (todo: replace with derive_builder)
+
+/// https://developer.github.com/v3/search/#search-topics
+///
+/// Topic search has no `sort` qualifier, so this only exposes `per_page`.
+#[derive(Default)]
+pub struct SearchTopicsOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl SearchTopicsOptions {
+    pub fn builder() -> SearchTopicsOptionsBuilder {
+        SearchTopicsOptionsBuilder::new()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+pub struct SearchTopicsOptionsBuilder(SearchTopicsOptions);
+
+impl SearchTopicsOptionsBuilder {
+    pub fn new() -> SearchTopicsOptionsBuilder {
+        SearchTopicsOptionsBuilder(SearchTopicsOptions { ..Default::default() })
+    }
+
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn build(&self) -> SearchTopicsOptions {
+        SearchTopicsOptions { params: self.0.params.clone() }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TopicItem {
+    pub name: String,
+    pub display_name: Option<String>,
+    pub short_description: Option<String>,
+    pub description: Option<String>,
+    pub created_by: Option<String>,
+    pub released: Option<String>,
+    #[serde(deserialize_with = "deserialize_date_time")]
+    pub created_at: DateTime<Utc>,
+    #[serde(deserialize_with = "deserialize_date_time")]
+    pub updated_at: DateTime<Utc>,
+    pub featured: bool,
+    pub curated: bool,
+    pub score: f64,
+}
@@ -0,0 +1,152 @@
+//! Search users interface
+
+use std::collections::HashMap;
+use std::fmt;
+
+use hyper::client::Connect;
+use url::form_urlencoded;
+
+use {Future, SortDirection, Stream};
+use search::{Search, SearchResult};
+
+/// Sort directions for user search results
+#[derive(Debug, PartialEq)]
+pub enum UsersSort {
+    /// Sort by number of followers
+    Followers,
+    /// Sort by number of repositories
+    Repositories,
+    /// Sort by date the user joined
+    Joined,
+}
+
+impl fmt::Display for UsersSort {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            UsersSort::Followers => "followers",
+            UsersSort::Repositories => "repositories",
+            UsersSort::Joined => "joined",
+        }.fmt(f)
+    }
+}
+
+/// Provides access to user search operations
+/// https://developer.github.com/v3/search/#search-users
+pub struct SearchUsers<C>
+where
+    C: Clone + Connect,
+{
+    search: Search<C>,
+}
+
+impl<C: Clone + Connect> SearchUsers<C> {
+    #[doc(hidden)]
+    pub fn new(search: Search<C>) -> Self {
+        Self { search }
+    }
+
+    fn search_uri<Q>(&self, q: Q, options: &SearchUsersOptions) -> String
+    where
+        Q: Into<String>,
+    {
+        let mut uri = vec!["/search/users".to_string()];
+        let query_options = options.serialize().unwrap_or(String::new());
+        let query = form_urlencoded::Serializer::new(query_options)
+            .append_pair("q", &q.into())
+            .finish();
+        uri.push(query);
+        uri.join("?")
+    }
+
+    /// Returns an Iterator over pages of search results
+    /// Use this interface if you wish to iterate over all items
+    /// in a result set
+    pub fn iter<Q>(&self, q: Q, options: &SearchUsersOptions) -> Stream<UsersItem>
+    where
+        Q: Into<String>,
+    {
+        self.search.iter::<UsersItem>(&self.search_uri(q, options))
+    }
+
+    /// Returns a single page of search results
+    pub fn list<Q>(&self, q: Q, options: &SearchUsersOptions) -> Future<SearchResult<UsersItem>>
+    where
+        Q: Into<String>,
+    {
+        self.search.search::<UsersItem>(
+            &self.search_uri(q, options),
+        )
+    }
+}
+
+// representations (todo: replace with derive_builder)
+
+#[derive(Default)]
+pub struct SearchUsersOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl SearchUsersOptions {
+    pub fn builder() -> SearchUsersOptionsBuilder {
+        SearchUsersOptionsBuilder::new()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+/// https://developer.github.com/v3/search/#search-users
+pub struct SearchUsersOptionsBuilder(SearchUsersOptions);
+
+impl SearchUsersOptionsBuilder {
+    pub fn new() -> SearchUsersOptionsBuilder {
+        SearchUsersOptionsBuilder(SearchUsersOptions { ..Default::default() })
+    }
+
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn sort(&mut self, sort: UsersSort) -> &mut Self {
+        self.0.params.insert("sort", sort.to_string());
+        self
+    }
+
+    pub fn order(&mut self, direction: SortDirection) -> &mut Self {
+        self.0.params.insert("order", direction.to_string());
+        self
+    }
+
+    pub fn build(&self) -> SearchUsersOptions {
+        SearchUsersOptions { params: self.0.params.clone() }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UsersItem {
+    pub login: String,
+    pub id: u64,
+    pub avatar_url: String,
+    pub gravatar_id: String,
+    pub url: String,
+    pub html_url: String,
+    pub followers_url: String,
+    pub subscriptions_url: String,
+    pub organizations_url: String,
+    pub repos_url: String,
+    pub received_events_url: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub site_admin: bool,
+    pub score: f64,
+}